@@ -92,6 +92,43 @@ impl DataRate {
             DataRate::Sps240 => Duration::from_micros(1_042),
         }
     }
+
+    /// Get the full-scale positive code returned by [`Ads1110::read_value_raw`](crate::Ads1110::read_value_raw)
+    /// at this data rate.
+    ///
+    /// The ADS1110 trades resolution for speed: the full 16-bit code range is only
+    /// available at 15sps, with fewer effective bits (and thus a smaller full-scale
+    /// code) at higher rates. See the table on [`Ads1110::read_value_raw`](crate::Ads1110::read_value_raw)
+    /// for the full breakdown.
+    pub fn full_scale_code(&self) -> i32 {
+        match self {
+            DataRate::Sps15 => 32_767,
+            DataRate::Sps30 => 16_383,
+            DataRate::Sps60 => 8_191,
+            DataRate::Sps240 => 2_047,
+        }
+    }
+}
+
+/// How long [`Ads1110::read_value_raw_with`](crate::Ads1110::read_value_raw_with) should
+/// wait for a fresh conversion before giving up with [`Error::Timeout`](crate::Error::Timeout).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ReadTimeout {
+    /// Wait up to `periods` multiples of the configured [`DataRate::interval`].
+    ///
+    /// The default, [`ReadTimeout::default`], is `Periods(1.25)`, matching the
+    /// "5/4 of a period" behavior of [`Ads1110::read_value_raw`](crate::Ads1110::read_value_raw).
+    Periods(f32),
+    /// Wait up to an explicit [`Duration`], regardless of the configured data rate.
+    ///
+    /// Useful for systems layering their own idle/deadline logic on top of this driver.
+    Explicit(Duration),
+}
+
+impl Default for ReadTimeout {
+    fn default() -> Self {
+        ReadTimeout::Periods(1.25)
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -130,6 +167,19 @@ pub enum Gain {
     X8,
 }
 
+impl Gain {
+    /// Get the PGA multiplier applied to the input differential voltage
+    /// before it is digitized.
+    pub fn multiplier(&self) -> i32 {
+        match self {
+            Gain::X1 => 1,
+            Gain::X2 => 2,
+            Gain::X4 => 4,
+            Gain::X8 => 8,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct WriteSettings {
     pub start: Start,
@@ -176,6 +226,156 @@ impl WriteSettings {
     }
 }
 
+/// A small in-driver smoothing filter, applied to a stream of millivolt readings.
+///
+/// This gives `no_std` users basic anti-noise shaping without pulling in a full DSP
+/// crate. All state is kept inline as `f32`, with no heap allocation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Filter {
+    /// A single-pole exponential moving average: `y[n] = y[n-1] + alpha*(x[n] - y[n-1])`.
+    Ewma {
+        /// Smoothing factor in `(0.0, 1.0]`. Smaller values smooth more aggressively.
+        alpha: f32,
+        /// Previous output, or `None` before the first sample has been seen.
+        y: Option<f32>,
+    },
+    /// A general Direct-Form-I biquad: `y = b0*x + b1*x1 + b2*x2 - a1*y1 - a2*y2`.
+    Biquad {
+        b0: f32,
+        b1: f32,
+        b2: f32,
+        a1: f32,
+        a2: f32,
+        x1: f32,
+        x2: f32,
+        y1: f32,
+        y2: f32,
+    },
+}
+
+impl Filter {
+    /// Create a new single-pole exponential moving average filter with the given
+    /// smoothing factor.
+    pub fn ewma(alpha: f32) -> Self {
+        Filter::Ewma { alpha, y: None }
+    }
+
+    /// Create a new Direct-Form-I biquad filter from its coefficients, with zeroed
+    /// initial state.
+    pub fn biquad(b0: f32, b1: f32, b2: f32, a1: f32, a2: f32) -> Self {
+        Filter::Biquad {
+            b0,
+            b1,
+            b2,
+            a1,
+            a2,
+            x1: 0.0,
+            x2: 0.0,
+            y1: 0.0,
+            y2: 0.0,
+        }
+    }
+
+    /// Push one new sample through the filter, updating its internal state, and
+    /// return the filtered output.
+    pub fn apply(&mut self, x: f32) -> f32 {
+        match self {
+            Filter::Ewma { alpha, y } => {
+                let out = match y {
+                    Some(prev) => *prev + *alpha * (x - *prev),
+                    None => x,
+                };
+                *y = Some(out);
+                out
+            }
+            Filter::Biquad {
+                b0,
+                b1,
+                b2,
+                a1,
+                a2,
+                x1,
+                x2,
+                y1,
+                y2,
+            } => {
+                let out = *b0 * x + *b1 * *x1 + *b2 * *x2 - *a1 * *y1 - *a2 * *y2;
+                *x2 = *x1;
+                *x1 = x;
+                *y2 = *y1;
+                *y1 = out;
+                out
+            }
+        }
+    }
+}
+
+/// A two-point offset/gain calibration, correcting a raw ADC code against a known
+/// reference.
+///
+/// The ADS1110 has some amount of input offset and gain error; this lets users null
+/// it out against a precision reference, via `corrected = (raw - offset_code) *
+/// scale_num / scale_den`. All arithmetic is integer-based, for `no_std` use.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Calibration {
+    pub offset_code: i16,
+    pub scale_num: i32,
+    pub scale_den: i32,
+}
+
+impl Default for Calibration {
+    /// The identity calibration: `corrected = raw`.
+    fn default() -> Self {
+        Self {
+            offset_code: 0,
+            scale_num: 1,
+            scale_den: 1,
+        }
+    }
+}
+
+impl Calibration {
+    /// Derive a [`Calibration`] from two measured (raw code, known reference) pairs.
+    ///
+    /// `low_ref`/`high_ref` are in whatever unit the caller wants the corrected value
+    /// in (e.g. raw codes, millivolts). Falls back to the identity [`Calibration`] if
+    /// `low_code == high_code` (no scale can be derived from a single point) or if
+    /// `low_ref == high_ref` (two distinct codes mapping to the same reference can't
+    /// be represented by this multiplicative correction: the only scale that fits is
+    /// zero, which would make [`Self::apply`] always return zero rather than the
+    /// constant reference value).
+    pub fn from_two_points(low_code: i16, low_ref: i32, high_code: i16, high_ref: i32) -> Self {
+        let scale_den = high_code as i32 - low_code as i32;
+        if scale_den == 0 {
+            return Self::default();
+        }
+        let scale_num = high_ref - low_ref;
+        if scale_num == 0 {
+            return Self::default();
+        }
+        let offset = low_code as i64 - (low_ref as i64 * scale_den as i64) / scale_num as i64;
+        Self {
+            offset_code: offset.clamp(i16::MIN as i64, i16::MAX as i64) as i16,
+            scale_num,
+            scale_den,
+        }
+    }
+
+    /// Apply this calibration to a raw ADC code.
+    ///
+    /// Guards against a zero `scale_den`, treating it as `1` (so the result is
+    /// `(raw - offset_code) * scale_num`) rather than dividing by zero. Computed in `i64` to avoid overflow and truncation before the
+    /// final division, mirroring [`crate::Ads1110::read_voltage_uv`]'s conversion.
+    pub fn apply(&self, raw: i16) -> i32 {
+        let den = if self.scale_den == 0 {
+            1
+        } else {
+            self.scale_den as i64
+        };
+        ((raw as i64 - self.offset_code as i64) * self.scale_num as i64 / den) as i32
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct ReadSettings {
     pub n_drdy: DataReady,
@@ -222,3 +422,74 @@ impl From<u8> for ReadSettings {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calibration_default_is_identity() {
+        let cal = Calibration::default();
+        assert_eq!(cal.apply(1234), 1234);
+        assert_eq!(cal.apply(-1234), -1234);
+    }
+
+    #[test]
+    fn calibration_from_two_points_recovers_both_points() {
+        let cal = Calibration::from_two_points(100, 1_000, 200, 3_000);
+        assert_eq!(cal.apply(100), 1_000);
+        assert_eq!(cal.apply(200), 3_000);
+        // Halfway between the two calibration codes should land halfway between
+        // the two reference values.
+        assert_eq!(cal.apply(150), 2_000);
+    }
+
+    #[test]
+    fn calibration_handles_full_scale_swing_without_overflow() {
+        // A legitimate "calibrate across the full 15sps swing" call.
+        let cal = Calibration::from_two_points(i16::MIN, 0, i16::MAX, 1_000);
+        assert_eq!(cal.apply(i16::MIN), 0);
+        assert_eq!(cal.apply(i16::MAX), 1_000);
+    }
+
+    #[test]
+    fn calibration_equal_codes_falls_back_to_identity() {
+        let cal = Calibration::from_two_points(100, 500, 100, 900);
+        assert_eq!(cal, Calibration::default());
+    }
+
+    #[test]
+    fn calibration_equal_references_falls_back_to_identity() {
+        // Two distinct codes that both measured the same reference value: there's no
+        // scale that can represent "always report 500" in this multiplicative model,
+        // so this must not silently degrade to an always-zero calibration.
+        let cal = Calibration::from_two_points(100, 500, 200, 500);
+        assert_eq!(cal, Calibration::default());
+        assert_eq!(cal.apply(100), 100);
+    }
+
+    #[test]
+    fn filter_ewma_converges_toward_constant_input() {
+        let mut filter = Filter::ewma(0.5);
+        let mut last = filter.apply(0.0);
+        for _ in 0..50 {
+            last = filter.apply(10.0);
+        }
+        assert!((last - 10.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn filter_ewma_first_sample_passes_through() {
+        let mut filter = Filter::ewma(0.1);
+        assert_eq!(filter.apply(42.0), 42.0);
+    }
+
+    #[test]
+    fn filter_biquad_identity_passthrough() {
+        // b0 = 1, all other coefficients 0: output should equal input every time.
+        let mut filter = Filter::biquad(1.0, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(filter.apply(1.0), 1.0);
+        assert_eq!(filter.apply(2.0), 2.0);
+        assert_eq!(filter.apply(3.0), 3.0);
+    }
+}
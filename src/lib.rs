@@ -8,12 +8,21 @@
 #![cfg_attr(not(test), no_std)]
 
 use config::{
-    Address, ConversionMode, DataRate, DataReady, Gain, ReadSettings, Start, WriteSettings,
+    Address, Calibration, ConversionMode, DataRate, DataReady, Filter, Gain, ReadSettings,
+    ReadTimeout, Start, WriteSettings,
 };
-use embassy_time::Timer;
+use embassy_time::{Duration, Timer};
 use embedded_hal_async::i2c::I2c;
+use futures::Stream;
 pub mod config;
 
+/// The ADS1110's fixed internal voltage reference, in millivolts.
+///
+/// The full-scale input range at a given [`Gain`] is `±VREF_MV / gain.multiplier()`.
+/// This is exposed so that users with an external resistor divider in front of the
+/// ADS1110's input can document and reuse it when deriving their own scaling.
+pub const VREF_MV: i32 = 2048;
+
 /// Driver error type
 #[derive(Debug, PartialEq)]
 pub enum Error<I: I2c> {
@@ -21,6 +30,8 @@ pub enum Error<I: I2c> {
     Timeout,
     /// An error with the underlying I2C bus
     I2c(I::Error),
+    /// An invalid `samples` count (zero) was passed to an averaging read
+    InvalidSampleCount,
 }
 
 /// Async driver for the ADS1110 ADC
@@ -39,6 +50,24 @@ async fn get_all<I: I2c>(i2c: &mut I, addr: u8) -> Result<[u8; 3], I::Error> {
     Ok(buf)
 }
 
+/// Convert a raw ADC code at the given `dr`/`pga` to millivolts.
+///
+/// See [`voltage_uv_from_code`] for the underlying conversion.
+fn voltage_mv_from_code(code: i32, dr: DataRate, pga: Gain) -> i32 {
+    voltage_uv_from_code(code, dr, pga) / 1_000
+}
+
+/// Convert a raw ADC code at the given `dr`/`pga` to microvolts.
+///
+/// `voltage_uv = code * VREF_MV * 1000 / (full_scale_code * pga_multiplier)`, computed
+/// in `i64` to avoid overflow and truncation before the final division.
+fn voltage_uv_from_code(code: i32, dr: DataRate, pga: Gain) -> i32 {
+    let full_scale = dr.full_scale_code() as i64;
+    let pga = pga.multiplier() as i64;
+    let numerator = code as i64 * VREF_MV as i64 * 1_000;
+    (numerator / (full_scale * pga)) as i32
+}
+
 impl<I> Ads1110<I>
 where
     I: I2c,
@@ -111,7 +140,40 @@ where
     ///
     /// This function does not consider `gain`, and returns only raw ADC counts
     pub async fn read_value_raw(&mut self) -> Result<i16, Error<I>> {
-        let mut quarter_waits = 0;
+        self.read_value_raw_with(ReadTimeout::default(), None).await
+    }
+
+    /// Attempts to get a raw value from the ADC, with a caller-chosen [`ReadTimeout`]
+    /// policy and poll cadence.
+    ///
+    /// This is the same as [Self::read_value_raw], except the deadline for a fresh
+    /// conversion is given by `timeout` (instead of always 5/4 of a period) and the
+    /// polling cadence is given by `poll_interval` (instead of always a quarter of a
+    /// period). Passing `None` for `poll_interval` falls back to [`DataRate::quarter_interval`].
+    ///
+    /// This matters for systems layering their own idle/deadline logic on top of this
+    /// driver: users running at 240sps on a slow/shared I2C bus may need a looser
+    /// deadline, while latency-sensitive users want tighter polling.
+    pub async fn read_value_raw_with(
+        &mut self,
+        timeout: ReadTimeout,
+        poll_interval: Option<Duration>,
+    ) -> Result<i16, Error<I>> {
+        let period = self.dr.interval();
+        let qperiod = poll_interval.unwrap_or_else(|| self.dr.quarter_interval());
+        let qperiod_us = qperiod.as_micros().max(1) as f32;
+
+        // Round `total_us / qperiod_us` to the nearest poll count. The tabulated
+        // `interval` and `quarter_interval` are each rounded to whole microseconds, so
+        // the quotient lands just off an integer (e.g. ~4.9999 at 15sps); truncating it
+        // would drop a whole poll and shorten the default `5/4 of a period` deadline.
+        let total_us = match timeout {
+            ReadTimeout::Periods(periods) => period.as_micros() as f32 * periods,
+            ReadTimeout::Explicit(d) => d.as_micros() as f32,
+        };
+        let max_polls = (total_us / qperiod_us).round().max(1.0) as u32;
+
+        let mut polls = 0;
 
         if let ConversionMode::OneShot = self.sc {
             // If we are in oneshot mode, start a conversion and wait
@@ -127,16 +189,14 @@ where
                 .write(self.addr, &[write])
                 .await
                 .map_err(Error::I2c)?;
-            let period = self.dr.interval();
 
             // Don't waste effort polling if we know it will take
             // a whole interval to finish a conversion.
             Timer::after(period).await;
-            quarter_waits = 4;
+            polls = (period.as_micros() as f32 / qperiod_us).round() as u32;
         }
 
-        // Wait up to 5/4 of a period
-        let qperiod = self.dr.quarter_interval();
+        // Wait up to the deadline given by `timeout`
         loop {
             let [data_hi, data_lo, config] = get_all(&mut self.i2c, self.addr)
                 .await
@@ -146,10 +206,10 @@ where
             if let DataReady::FreshData = read.n_drdy {
                 return Ok(i16::from_be_bytes([data_hi, data_lo]));
             }
-            if quarter_waits >= 5 {
+            if polls >= max_polls {
                 return Err(Error::Timeout);
             }
-            quarter_waits += 1;
+            polls += 1;
             Timer::after(qperiod).await;
         }
     }
@@ -174,8 +234,191 @@ where
         })
     }
 
+    /// Attempt to read the ADC, converted to the input differential voltage in millivolts.
+    ///
+    /// This accounts for both the configured [`Gain`] and the ADS1110's fixed
+    /// [`VREF_MV`] internal reference: `voltage_mv = raw_code * VREF_MV / (full_scale_code * gain)`.
+    ///
+    /// See [Self::read_value_raw] for the conversion timing and timeout behavior.
+    pub async fn read_voltage_mv(&mut self) -> Result<i32, Error<I>> {
+        let raw = self.read_value_raw().await?;
+        Ok(self.code_to_voltage_mv(raw as i32))
+    }
+
+    /// Attempt to read the ADC, converted to the input differential voltage in microvolts.
+    ///
+    /// This is the same conversion as [Self::read_voltage_mv], but retains finer
+    /// precision for callers that need it.
+    pub async fn read_voltage_uv(&mut self) -> Result<i32, Error<I>> {
+        let raw = self.read_value_raw().await?;
+        Ok(self.code_to_voltage_uv(raw as i32))
+    }
+
+    /// Attempt to read the ADC `samples` times, and return the arithmetic mean of the
+    /// raw codes.
+    ///
+    /// At the higher data rates the ADS1110 only delivers 12-14 effective bits (see
+    /// [Self::read_value_raw]), so averaging several conversions is the standard way
+    /// to recover resolution at the cost of latency.
+    ///
+    /// In "OneShot" mode, each sample starts and waits for its own conversion, same
+    /// as a plain [Self::read_value_raw] call. In "Continuous" mode, each sample waits
+    /// for its own fresh [`DataReady`] result, so no reading is double-counted.
+    ///
+    /// `samples` must be non-zero, or [`Error::InvalidSampleCount`] is returned. The
+    /// accumulator is a wider `i32` to avoid overflow before dividing.
+    pub async fn read_value_averaged(&mut self, samples: u16) -> Result<i16, Error<I>> {
+        if samples == 0 {
+            return Err(Error::InvalidSampleCount);
+        }
+        let mut acc: i32 = 0;
+        for _ in 0..samples {
+            acc += self.read_value_raw().await? as i32;
+        }
+        Ok((acc / samples as i32) as i16)
+    }
+
+    /// Attempt to read the ADC `samples` times, and return the input differential
+    /// voltage in millivolts, averaged over the raw codes.
+    ///
+    /// See [Self::read_value_averaged] for the averaging behavior, and
+    /// [Self::read_voltage_mv] for the voltage conversion.
+    pub async fn read_voltage_mv_averaged(&mut self, samples: u16) -> Result<i32, Error<I>> {
+        let raw = self.read_value_averaged(samples).await?;
+        Ok(self.code_to_voltage_mv(raw as i32))
+    }
+
+    /// Convert a raw ADC code (at the currently configured `dr`/`pga`) to millivolts.
+    fn code_to_voltage_mv(&self, code: i32) -> i32 {
+        voltage_mv_from_code(code, self.dr, self.pga)
+    }
+
+    /// Convert a raw ADC code (at the currently configured `dr`/`pga`) to microvolts.
+    fn code_to_voltage_uv(&self, code: i32) -> i32 {
+        voltage_uv_from_code(code, self.dr, self.pga)
+    }
+
+    /// Attempt to read the ADC, and apply a two-point [`Calibration`] to the raw code.
+    ///
+    /// See [`Calibration::apply`] for the correction formula.
+    pub async fn read_value_calibrated(&mut self, cal: &Calibration) -> Result<i32, Error<I>> {
+        let raw = self.read_value_raw().await?;
+        Ok(cal.apply(raw))
+    }
+
+    /// Attempt to read the ADC, apply a two-point [`Calibration`] to the raw code, and
+    /// convert the corrected code to millivolts.
+    pub async fn read_voltage_calibrated(&mut self, cal: &Calibration) -> Result<i32, Error<I>> {
+        let raw = self.read_value_raw().await?;
+        let corrected = cal.apply(raw);
+        Ok(self.code_to_voltage_mv(corrected))
+    }
+
+    /// Attempt to read the ADC, converted to millivolts, and pass it through a
+    /// caller-supplied [`Filter`].
+    ///
+    /// This takes one conversion (see [Self::read_voltage_mv]), updates the filter's
+    /// internal state with it, and returns the filtered value. Keeping the same
+    /// `filter` across calls lets it smooth a stream of readings.
+    pub async fn read_voltage_filtered(&mut self, filter: &mut Filter) -> Result<f32, Error<I>> {
+        let mv = self.read_voltage_mv().await?;
+        Ok(filter.apply(mv as f32))
+    }
+
+    /// Wait for, and return, the next fresh conversion.
+    ///
+    /// This is intended for use while [`ConversionMode::Continuous`] is configured: it
+    /// polls at [`DataRate::quarter_interval`] and only returns once a [`DataReady::FreshData`]
+    /// result is observed, so each call yields a sample exactly once (never a stale
+    /// repeat of the last one). Timeout behavior matches [Self::read_value_raw]: an
+    /// [`Error::Timeout`] is returned if no fresh data arrives within 5/4 of a period.
+    ///
+    /// Calling this while in [`ConversionMode::OneShot`] also works (it will start a
+    /// conversion, same as [Self::read_value_raw]), but [Self::sample_stream] only
+    /// makes sense as a pull-based replacement for polling loops in continuous mode.
+    pub async fn next_sample(&mut self) -> Result<i16, Error<I>> {
+        self.read_value_raw().await
+    }
+
+    /// Get a pull-based [`Stream`] of fresh conversions.
+    ///
+    /// This is a thin wrapper around repeatedly calling [Self::next_sample], for users
+    /// who want to `.await` on a stream of samples rather than hand-writing a polling
+    /// loop. See [Self::next_sample] for the per-item timing and timeout behavior.
+    pub fn sample_stream(&mut self) -> impl Stream<Item = Result<i16, Error<I>>> + '_ {
+        futures::stream::unfold(self, |dev| async move {
+            let sample = dev.next_sample().await;
+            Some((sample, dev))
+        })
+    }
+
     /// Give back the I2C bus
     pub fn release(self) -> I {
         self.i2c
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The full-scale code is, by construction, the code that saturates the input
+    // range, so it should convert to exactly `VREF_MV` at unity gain.
+    #[test]
+    fn voltage_full_scale_x1() {
+        let mv = voltage_mv_from_code(DataRate::Sps15.full_scale_code(), DataRate::Sps15, Gain::X1);
+        assert_eq!(mv, VREF_MV);
+    }
+
+    // Higher gain shrinks the full-scale input range, so the same full-scale code
+    // should report a proportionally smaller voltage.
+    #[test]
+    fn voltage_scales_with_gain() {
+        let code = DataRate::Sps15.full_scale_code();
+        let x1 = voltage_mv_from_code(code, DataRate::Sps15, Gain::X1);
+        let x2 = voltage_mv_from_code(code, DataRate::Sps15, Gain::X2);
+        let x4 = voltage_mv_from_code(code, DataRate::Sps15, Gain::X4);
+        let x8 = voltage_mv_from_code(code, DataRate::Sps15, Gain::X8);
+        assert_eq!(x1, x2 * 2);
+        assert_eq!(x1, x4 * 4);
+        assert_eq!(x1, x8 * 8);
+    }
+
+    // Every data rate's full-scale code is, by definition, the denominator of its own
+    // conversion, so each should independently convert back to exactly `VREF_MV`.
+    #[test]
+    fn voltage_full_scale_matches_reference_at_every_data_rate() {
+        for dr in [
+            DataRate::Sps15,
+            DataRate::Sps30,
+            DataRate::Sps60,
+            DataRate::Sps240,
+        ] {
+            let full_scale_mv = voltage_mv_from_code(dr.full_scale_code(), dr, Gain::X1);
+            assert_eq!(full_scale_mv, VREF_MV, "{dr:?}");
+        }
+    }
+
+    // A higher data rate has a smaller full-scale code, so the same raw code should
+    // report a larger voltage as the data rate increases (240sps has only 12 bits).
+    #[test]
+    fn voltage_scales_with_data_rate() {
+        let code = 100;
+        let mv_15 = voltage_mv_from_code(code, DataRate::Sps15, Gain::X1);
+        let mv_240 = voltage_mv_from_code(code, DataRate::Sps240, Gain::X1);
+        assert!(mv_240 > mv_15);
+    }
+
+    #[test]
+    fn voltage_zero_code_is_zero() {
+        assert_eq!(voltage_mv_from_code(0, DataRate::Sps240, Gain::X8), 0);
+    }
+
+    #[test]
+    fn voltage_uv_is_finer_than_mv() {
+        let uv = voltage_uv_from_code(1, DataRate::Sps15, Gain::X1);
+        // One raw count at 15sps/X1 is well under a millivolt.
+        assert_eq!(voltage_mv_from_code(1, DataRate::Sps15, Gain::X1), 0);
+        assert!(uv > 0);
+    }
+}